@@ -1,7 +1,12 @@
 //! stolen from pixelpwnr
 
+use anyhow::anyhow;
+use anyhow::Result;
 use random::Source;
 use random::Value;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
 
 /// Color struct.
 ///
@@ -27,6 +32,38 @@ impl Color {
     pub fn as_hex(self) -> String {
         format!("{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
     }
+
+    /// Parses the hexadecimal representation produced by [`Color::as_hex`], either with or
+    /// without the trailing alpha channel (`RRGGBB` defaults to opaque).
+    pub fn from_hex(hex: &str) -> Result<Color> {
+        let hex = hex.trim_start_matches('#');
+        if hex.len() != 6 && hex.len() != 8 {
+            return Err(anyhow!("color {hex:?} must be 6 or 8 hex digits"));
+        }
+        let channel = |range: std::ops::Range<usize>| -> Result<u8> {
+            let digits = hex
+                .get(range)
+                .ok_or_else(|| anyhow!("color {hex:?} must be 6 or 8 hex digits"))?;
+            Ok(u8::from_str_radix(digits, 16)?)
+        };
+        let a = if hex.len() == 8 { channel(6..8)? } else { 0xff };
+        Ok(Color {
+            r: channel(0..2)?,
+            g: channel(2..4)?,
+            b: channel(4..6)?,
+            a,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        Color::from_hex(&hex).map_err(D::Error::custom)
+    }
 }
 
 impl Value for Color {
@@ -0,0 +1,66 @@
+//! Tunable game parameters, loaded from a JSON5 file so the game can be adjusted for a given
+//! pixelflut wall's resolution and refresh rate without recompiling.
+
+use crate::color::Color;
+use serde::Deserialize;
+use std::f32::consts::FRAC_PI_4;
+use std::time::Duration;
+
+#[derive(Deserialize, Clone)]
+#[serde(default)]
+pub struct Config {
+    /// Height of a paddle, as a fraction of the screen height.
+    pub paddle_height_fraction: f32,
+    /// Width of a paddle, as a fraction of the screen width.
+    pub paddle_width_fraction: f32,
+    /// Diameter of the ball, as a fraction of the shorter screen dimension.
+    pub ball_size_fraction: f32,
+    /// Ball speed in pixels per tick, as a fraction of the screen width.
+    pub ball_speed_fraction: f32,
+    /// Paddle speed in pixels per tick, as a fraction of the screen height.
+    pub paddle_speed_fraction: f32,
+    /// Maximum angle, in radians, the ball can be reflected off a paddle.
+    pub max_reflect_angle: f32,
+    #[serde(deserialize_with = "deserialize_frame_time")]
+    pub frame_time: Duration,
+    pub object_color: Color,
+    /// Border thickness of ball and paddles, as a fraction of the shorter screen dimension.
+    pub border_width_fraction: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            paddle_height_fraction: 1.0 / 7.0,
+            paddle_width_fraction: 1.0 / 40.0,
+            ball_size_fraction: 1.0 / 20.0,
+            ball_speed_fraction: 1.0 / 50.0,
+            paddle_speed_fraction: 1.0 / 85.0,
+            max_reflect_angle: FRAC_PI_4,
+            frame_time: Duration::from_millis(1000 / 30),
+            object_color: Color {
+                r: 0xff,
+                g: 0,
+                b: 0xff,
+                a: 0xff,
+            },
+            border_width_fraction: 1.0 / 320.0,
+        }
+    }
+}
+
+impl Config {
+    /// Loads a config from a JSON5 file, falling back to defaults for any field it omits.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(json5::from_str(&text)?)
+    }
+}
+
+/// Deserializes `Duration` as a millisecond count, matching how `FRAME_TIME` used to be
+/// expressed as a `const`.
+fn deserialize_frame_time<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+}
@@ -5,10 +5,10 @@ use anyhow::anyhow;
 use anyhow::Result;
 use client::Client;
 use color::Color;
+use config::Config;
 use glam::I16Vec2;
 use glam::U16Vec2;
 use input::Key;
-use std::f32::consts::FRAC_PI_4;
 use std::f32::consts::PI;
 use std::net::TcpStream;
 use std::sync::Arc;
@@ -19,30 +19,21 @@ use std::time::Instant;
 
 mod client;
 mod color;
+mod config;
 mod input;
 
-/// Height of a paddle.
-const PADDLE_HEIGHT_FRACTION: f32 = 1.0 / 7.0;
-/// Width of a paddle.
-const PADDLE_WIDTH: i16 = 47;
 /// Gap to left or right.
 const PADDLE_GAP_FRACTION: f32 = 1.0 / 9.0;
-/// Size of the ball.
-const BALL_SIZE: i16 = 58;
-const BALL_SPEED: f32 = 30.0;
-const PADDLE_SPEED: i16 = 17;
 
-// 75 degrees
-const MAX_REFLECT_ANGLE: f32 = FRAC_PI_4;
+/// Default AI difficulty when `--ai1`/`--ai2` is given without an explicit value.
+const DEFAULT_AI_DIFFICULTY: f32 = 0.6;
 
-const FRAME_TIME: Duration = Duration::from_millis(1000 / 30);
-
-const OBJECT_COLOR: Color = Color {
-    r: 0xff,
-    g: 0,
-    b: 0xff,
-    a: 0xff,
-};
+/// Extra reflection angle, in radians, added per unit of paddle velocity sign on impact.
+const SPIN_STRENGTH: f32 = 0.12;
+/// Fraction of the initial ball speed added to `ball_speed` on every paddle hit.
+const BALL_SPEED_GAIN_FRACTION: f32 = 0.08;
+/// Cap on `ball_speed`, as a multiple of the initial ball speed, so rallies don't accelerate forever.
+const MAX_BALL_SPEED_MULTIPLIER: f32 = 2.0;
 
 const BLACK: Color = Color {
     r: 0,
@@ -50,7 +41,35 @@ const BLACK: Color = Color {
     b: 0,
     a: 0xff,
 };
-const BORDER_WIDTH: i16 = 6;
+
+/// Width, in font cells, of a scoreboard digit.
+const DIGIT_WIDTH: i16 = 3;
+/// Height, in font cells, of a scoreboard digit.
+const DIGIT_HEIGHT: i16 = 5;
+/// How many screen pixels each font cell is scaled up to.
+const SCORE_SCALE: i16 = 6;
+
+/// 3x5 bitmap font for the digits 0-9, one `u8` per row with the 3 columns in its low bits.
+const DIGIT_FONT: [[u8; DIGIT_HEIGHT as usize]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111], // 0
+    [0b010, 0b110, 0b010, 0b010, 0b111], // 1
+    [0b111, 0b001, 0b111, 0b100, 0b111], // 2
+    [0b111, 0b001, 0b111, 0b001, 0b111], // 3
+    [0b101, 0b101, 0b111, 0b001, 0b001], // 4
+    [0b111, 0b100, 0b111, 0b001, 0b111], // 5
+    [0b111, 0b100, 0b111, 0b101, 0b111], // 6
+    [0b111, 0b001, 0b010, 0b010, 0b010], // 7
+    [0b111, 0b101, 0b111, 0b101, 0b111], // 8
+    [0b111, 0b101, 0b111, 0b001, 0b111], // 9
+];
+
+/// Which paddle, if any, is driven by the computer instead of a keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AiControl {
+    None,
+    Player1,
+    Player2,
+}
 
 #[derive(Clone, Copy, Debug)]
 enum PlayerDir {
@@ -86,6 +105,15 @@ impl PlayerDir {
             PlayerDir::Up | PlayerDir::Both => PlayerDir::Up,
         }
     }
+
+    /// Sign of the paddle's vertical velocity: `-1` moving up, `1` moving down, `0` otherwise.
+    pub const fn velocity_sign(self) -> i16 {
+        match self {
+            PlayerDir::Up => -1,
+            PlayerDir::Down => 1,
+            PlayerDir::Neutral | PlayerDir::Both => 0,
+        }
+    }
 }
 
 struct GameState {
@@ -105,12 +133,47 @@ struct GameState {
     player2_dir: PlayerDir,
 
     paddle_height: i16,
+
+    /// Which paddle, if any, is computer-controlled.
+    ai_control: AiControl,
+    /// Fraction of `paddle_speed` the AI paddle is allowed to move per tick, in `(0.0, 1.0]`.
+    ai_difficulty: f32,
+
+    player1_score: u32,
+    player2_score: u32,
+
+    /// Width of a paddle in pixels, derived from `config.paddle_width_fraction` and `size`.
+    paddle_width: i16,
+    /// Diameter of the ball in pixels, derived from `config.ball_size_fraction` and `size`.
+    ball_size: i16,
+    /// Border thickness in pixels, derived from `config.border_width_fraction` and `size`.
+    border_width: i16,
+    /// Ball speed in pixels per tick, derived from `config.ball_speed_fraction` and `size`.
+    /// Mutable so rallies can speed it up on paddle hits.
+    ball_speed: f32,
+    /// `ball_speed` at game start, used as the reference point for `MAX_BALL_SPEED_MULTIPLIER`.
+    base_ball_speed: f32,
+    /// Paddle speed in pixels per tick, derived from `config.paddle_speed_fraction` and `size`.
+    paddle_speed: i16,
+
+    config: Arc<Config>,
 }
 
 impl GameState {
-    pub fn new(size: I16Vec2) -> SharedGameState {
-        let paddle_height = (PADDLE_HEIGHT_FRACTION * size.y as f32).floor() as i16;
+    pub fn new(
+        size: I16Vec2,
+        ai_control: AiControl,
+        ai_difficulty: f32,
+        config: Arc<Config>,
+    ) -> SharedGameState {
+        let paddle_height = (config.paddle_height_fraction * size.y as f32).floor() as i16;
         let paddle_y = (size.y - paddle_height) / 2;
+        let base_dim = size.x.min(size.y);
+        let paddle_width = (config.paddle_width_fraction * size.x as f32).floor().max(1.0) as i16;
+        let ball_size = (config.ball_size_fraction * base_dim as f32).floor().max(1.0) as i16;
+        let border_width = (config.border_width_fraction * base_dim as f32).floor().max(1.0) as i16;
+        let ball_speed = config.ball_speed_fraction * size.x as f32;
+        let paddle_speed = (config.paddle_speed_fraction * size.y as f32).round() as i16;
         Arc::new(RwLock::new(Self {
             size,
             ball: size / 2,
@@ -120,26 +183,114 @@ impl GameState {
                 paddle_y,
             ),
             player2: I16Vec2::new(
-                size.x - (PADDLE_GAP_FRACTION * size.x as f32).floor() as i16 - PADDLE_WIDTH,
+                size.x - (PADDLE_GAP_FRACTION * size.x as f32).floor() as i16 - paddle_width,
                 paddle_y,
             ),
             ball_is_moving: false,
             player1_dir: PlayerDir::Neutral,
             player2_dir: PlayerDir::Neutral,
             paddle_height,
+            ai_control,
+            ai_difficulty,
+            player1_score: 0,
+            player2_score: 0,
+            paddle_width,
+            ball_size,
+            border_width,
+            ball_speed,
+            base_ball_speed: ball_speed,
+            paddle_speed,
+            config,
         }))
     }
 
+    /// How far the ball center may drift from the AI paddle's center before it reacts. Keeps
+    /// the AI from twitching back and forth when the ball is roughly in front of it.
+    fn ai_dead_zone(&self) -> i16 {
+        self.paddle_speed * 3
+    }
+
+    /// Per-tick movement speed of the AI paddle: `self.paddle_speed` scaled by `ai_difficulty`,
+    /// rounded and at least one pixel. A difficulty of `1.0` moves at the full paddle speed.
+    fn ai_speed(&self) -> i16 {
+        ((self.paddle_speed as f32) * self.ai_difficulty)
+            .round()
+            .max(1.0) as i16
+    }
+
+    /// Decides the AI paddle's direction for this tick by comparing the paddle's vertical
+    /// center to the ball's, but only reacts once the ball is actually headed towards it.
+    fn ai_direction(
+        paddle_y: i16,
+        paddle_height: i16,
+        ball: I16Vec2,
+        ball_angle: f32,
+        dead_zone: i16,
+        is_player2: bool,
+    ) -> PlayerDir {
+        let heading_towards_paddle = if is_player2 {
+            ball_angle.cos() > 0.0
+        } else {
+            ball_angle.cos() < 0.0
+        };
+        if !heading_towards_paddle {
+            return PlayerDir::Neutral;
+        }
+
+        let paddle_center = paddle_y + paddle_height / 2;
+        let offset = ball.y - paddle_center;
+        if offset > dead_zone {
+            PlayerDir::Down
+        } else if offset < -dead_zone {
+            PlayerDir::Up
+        } else {
+            PlayerDir::Neutral
+        }
+    }
+
     pub fn update(&mut self) {
+        let ai_dead_zone = self.ai_dead_zone();
+        if self.ai_control == AiControl::Player1 {
+            self.player1_dir = Self::ai_direction(
+                self.player1.y,
+                self.paddle_height,
+                self.ball,
+                self.ball_angle,
+                ai_dead_zone,
+                false,
+            );
+        }
+        if self.ai_control == AiControl::Player2 {
+            self.player2_dir = Self::ai_direction(
+                self.player2.y,
+                self.paddle_height,
+                self.ball,
+                self.ball_angle,
+                ai_dead_zone,
+                true,
+            );
+        }
+
+        let player1_speed = if self.ai_control == AiControl::Player1 {
+            self.ai_speed()
+        } else {
+            self.paddle_speed
+        };
+        let player2_speed = if self.ai_control == AiControl::Player2 {
+            self.ai_speed()
+        } else {
+            self.paddle_speed
+        };
+
         self.player1 += I16Vec2::from(match self.player1_dir {
-            PlayerDir::Up => (0i16, -PADDLE_SPEED),
-            PlayerDir::Down => (0, PADDLE_SPEED),
+            PlayerDir::Up => (0i16, -player1_speed),
+            PlayerDir::Down => (0, player1_speed),
             PlayerDir::Neutral | PlayerDir::Both => (0, 0),
         });
 
         self.player2 += I16Vec2::from(match self.player2_dir {
-            PlayerDir::Up => (0i16, -PADDLE_SPEED),
-            PlayerDir::Down => (0, PADDLE_SPEED),
+            PlayerDir::Up => (0i16, -player2_speed),
+            PlayerDir::Down => (0, player2_speed),
             PlayerDir::Neutral | PlayerDir::Both => (0, 0),
         });
 
@@ -148,8 +299,8 @@ impl GameState {
 
         if self.ball_is_moving {
             self.ball += I16Vec2 {
-                x: (self.ball_angle.cos() * BALL_SPEED) as i16,
-                y: (self.ball_angle.sin() * BALL_SPEED) as i16,
+                x: (self.ball_angle.cos() * self.ball_speed) as i16,
+                y: (self.ball_angle.sin() * self.ball_speed) as i16,
             };
         }
 
@@ -160,7 +311,7 @@ impl GameState {
 
         // player 1 ball collision
         if self.ball.x > self.player1.x
-            && self.ball.x < self.player1.x + PADDLE_WIDTH
+            && self.ball.x < self.player1.x + self.paddle_width
             && self.ball.y > self.player1.y
             && self.ball.y < self.player1.y + self.paddle_height
             && self.ball_angle.cos() < 0.0
@@ -168,13 +319,16 @@ impl GameState {
             // [-1, 1] normalized position of the collision relative to the center
             let collision_pos = -((self.paddle_height / 2) - (self.ball.y - self.player1.y)) as f32
                 / (self.paddle_height / 2) as f32;
-            let collision_angle = MAX_REFLECT_ANGLE * collision_pos;
+            let spin = SPIN_STRENGTH * self.player1_dir.velocity_sign() as f32;
+            let collision_angle = self.config.max_reflect_angle * collision_pos + spin;
             self.ball_angle = collision_angle;
+            self.ball_speed = (self.ball_speed + self.base_ball_speed * BALL_SPEED_GAIN_FRACTION)
+                .min(self.base_ball_speed * MAX_BALL_SPEED_MULTIPLIER);
         }
 
         // player 2 ball collision
         if self.ball.x > self.player2.x
-            && self.ball.x < self.player2.x + PADDLE_WIDTH
+            && self.ball.x < self.player2.x + self.paddle_width
             && self.ball.y > self.player2.y
             && self.ball.y < self.player2.y + self.paddle_height
             && self.ball_angle.cos() > 0.0
@@ -182,9 +336,15 @@ impl GameState {
             // [-1, 1] normalized position of the collision relative to the center
             let collision_pos = ((self.paddle_height / 2) - (self.ball.y - self.player2.y)) as f32
                 / (self.paddle_height / 2) as f32;
-            let collision_angle = MAX_REFLECT_ANGLE * collision_pos - PI;
+            // Player 2's base angle sits near `-PI`, where increasing the angle rotates the
+            // ball's vertical component the opposite way it does near player 1's base angle of
+            // `0` - negate the spin here so paddle-down still imparts downward spin on both sides.
+            let spin = SPIN_STRENGTH * self.player2_dir.velocity_sign() as f32;
+            let collision_angle = self.config.max_reflect_angle * collision_pos - PI - spin;
 
             self.ball_angle = collision_angle;
+            self.ball_speed = (self.ball_speed + self.base_ball_speed * BALL_SPEED_GAIN_FRACTION)
+                .min(self.base_ball_speed * MAX_BALL_SPEED_MULTIPLIER);
         }
 
         // player 1 scores
@@ -192,12 +352,16 @@ impl GameState {
             self.ball_is_moving = false;
             self.ball = self.size / 2 + I16Vec2::new(self.size.x / 3, 0);
             self.ball_angle = PI;
+            self.ball_speed = self.base_ball_speed;
+            self.player1_score += 1;
         }
         // player 2 scores
         else if self.ball.x < 0 {
             self.ball_is_moving = false;
             self.ball = self.size / 2 - I16Vec2::new(self.size.x / 3, 0);
             self.ball_angle = 0.0;
+            self.ball_speed = self.base_ball_speed;
+            self.player2_score += 1;
         }
     }
 
@@ -222,27 +386,120 @@ impl GameState {
 
 type SharedGameState = Arc<RwLock<GameState>>;
 
-fn draw_ball(game: SharedGameState, server: String) -> Result<()> {
+/// Splits the part of the `old_top_left`..`old_top_left+size` box that isn't covered by the
+/// `new_top_left`..`new_top_left+size` box into up to four non-overlapping rectangles (a thin
+/// strip on whichever sides moved), so a moving object can erase only the pixels it vacated
+/// instead of redrawing its whole bounding box every frame.
+fn rect_difference(
+    old_top_left: I16Vec2,
+    new_top_left: I16Vec2,
+    size: I16Vec2,
+) -> Vec<(I16Vec2, I16Vec2)> {
+    let old_bottom_right = old_top_left + size;
+    let new_bottom_right = new_top_left + size;
+
+    let overlap_top_left = old_top_left.max(new_top_left);
+    let overlap_bottom_right = old_bottom_right.min(new_bottom_right);
+
+    // The old and new boxes don't overlap at all (e.g. the ball teleported on a score reset, or
+    // a fast-moving object outran its own size) - there is no strip math to do, just erase the
+    // object's entire previous box.
+    if overlap_top_left.x >= overlap_bottom_right.x || overlap_top_left.y >= overlap_bottom_right.y
+    {
+        return vec![(old_top_left, old_bottom_right)];
+    }
+
+    let mut rects = Vec::with_capacity(4);
+    if overlap_top_left.y > old_top_left.y {
+        // strip above the overlap
+        rects.push((
+            old_top_left,
+            I16Vec2::new(old_bottom_right.x, overlap_top_left.y),
+        ));
+    }
+    if old_bottom_right.y > overlap_bottom_right.y {
+        // strip below the overlap
+        rects.push((
+            I16Vec2::new(old_top_left.x, overlap_bottom_right.y),
+            old_bottom_right,
+        ));
+    }
+    if overlap_top_left.x > old_top_left.x {
+        // strip left of the overlap, restricted to the overlapping rows
+        rects.push((
+            I16Vec2::new(old_top_left.x, overlap_top_left.y),
+            I16Vec2::new(overlap_top_left.x, overlap_bottom_right.y),
+        ));
+    }
+    if old_bottom_right.x > overlap_bottom_right.x {
+        // strip right of the overlap, restricted to the overlapping rows
+        rects.push((
+            I16Vec2::new(overlap_bottom_right.x, overlap_top_left.y),
+            I16Vec2::new(old_bottom_right.x, overlap_bottom_right.y),
+        ));
+    }
+    rects
+}
+
+/// Erases the pixels `rect_difference(old_top_left, new_top_left, size)` covers by painting
+/// them `BLACK`, i.e. the part of the object's previous position its new position doesn't
+/// overlap.
+fn erase_vacated(
+    client: &mut Client,
+    old_top_left: I16Vec2,
+    new_top_left: I16Vec2,
+    size: I16Vec2,
+) -> Result<()> {
+    for (top_left, bottom_right) in rect_difference(old_top_left, new_top_left, size) {
+        for x in top_left.x..bottom_right.x {
+            for y in top_left.y..bottom_right.y {
+                client.write_pixel(x as u16, y as u16, BLACK)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn draw_ball(game: SharedGameState, server: String, config: Arc<Config>) -> Result<()> {
     let mut client = Client::new(TcpStream::connect(server)?, false, true);
 
     // let mut random = random::default(SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_micros() as u64);
 
+    let (ball_size, border_width) = {
+        let game = game.read().unwrap();
+        (game.ball_size, game.border_width)
+    };
+    let mut last_upper_left: Option<I16Vec2> = None;
+
     loop {
         let game = game.read().unwrap();
         let ball_pos = game.ball;
         drop(game);
 
         // let color: Color = random.read();
-        let color = OBJECT_COLOR;
-        let upper_left = ball_pos - BALL_SIZE / 2;
-        for x in 0..BALL_SIZE {
-            for y in 0..BALL_SIZE {
+        let color = config.object_color;
+        let upper_left = ball_pos - ball_size / 2;
+
+        if let Some(last_upper_left) = last_upper_left {
+            if last_upper_left != upper_left {
+                erase_vacated(
+                    &mut client,
+                    last_upper_left,
+                    upper_left,
+                    I16Vec2::splat(ball_size),
+                )?;
+            }
+        }
+        last_upper_left = Some(upper_left);
+
+        for x in 0..ball_size {
+            for y in 0..ball_size {
                 let pos = upper_left + I16Vec2::new(x, y);
 
-                let color = if x < BORDER_WIDTH
-                    || x > BALL_SIZE - BORDER_WIDTH
-                    || y < BORDER_WIDTH
-                    || y > BALL_SIZE - BORDER_WIDTH
+                let color = if x < border_width
+                    || x > ball_size - border_width
+                    || y < border_width
+                    || y > ball_size - border_width
                 {
                     BLACK
                 } else {
@@ -254,13 +511,18 @@ fn draw_ball(game: SharedGameState, server: String) -> Result<()> {
         }
     }
 }
-fn draw_players(game: SharedGameState, server: String) -> Result<()> {
+fn draw_players(game: SharedGameState, server: String, config: Arc<Config>) -> Result<()> {
     let mut client = Client::new(TcpStream::connect(server)?, false, true);
 
     // let mut random = random::default(SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_micros() as u64);
 
-    let paddle_height =
-        (PADDLE_HEIGHT_FRACTION * game.read().unwrap().size.y as f32).floor() as i16;
+    let (paddle_width, border_width, paddle_height) = {
+        let game = game.read().unwrap();
+        (game.paddle_width, game.border_width, game.paddle_height)
+    };
+    let paddle_size = I16Vec2::new(paddle_width, paddle_height);
+    let mut last_player1_pos: Option<I16Vec2> = None;
+    let mut last_player2_pos: Option<I16Vec2> = None;
 
     loop {
         let game = game.read().unwrap();
@@ -268,16 +530,29 @@ fn draw_players(game: SharedGameState, server: String) -> Result<()> {
         let player2_pos = game.player2;
         drop(game);
 
+        if let Some(last_player1_pos) = last_player1_pos {
+            if last_player1_pos != player1_pos {
+                erase_vacated(&mut client, last_player1_pos, player1_pos, paddle_size)?;
+            }
+        }
+        if let Some(last_player2_pos) = last_player2_pos {
+            if last_player2_pos != player2_pos {
+                erase_vacated(&mut client, last_player2_pos, player2_pos, paddle_size)?;
+            }
+        }
+        last_player1_pos = Some(player1_pos);
+        last_player2_pos = Some(player2_pos);
+
         // let color: Color = random.read();
-        let color = OBJECT_COLOR;
-        for x in 0..PADDLE_WIDTH {
+        let color = config.object_color;
+        for x in 0..paddle_width {
             for y in 0..paddle_height {
                 let pos1 = player1_pos + I16Vec2::new(x, y);
                 let pos2 = player2_pos + I16Vec2::new(x, y);
-                let color = if x < BORDER_WIDTH
-                    || x > PADDLE_WIDTH - BORDER_WIDTH
-                    || y < BORDER_WIDTH
-                    || y > paddle_height - BORDER_WIDTH
+                let color = if x < border_width
+                    || x > paddle_width - border_width
+                    || y < border_width
+                    || y > paddle_height - border_width
                 {
                     BLACK
                 } else {
@@ -290,6 +565,61 @@ fn draw_players(game: SharedGameState, server: String) -> Result<()> {
     }
 }
 
+/// Draws a single scoreboard digit at `top_left`, scaled up by `scale`, using `color` for set
+/// bits of the 3x5 font and `BLACK` elsewhere.
+fn draw_digit(client: &mut Client, top_left: I16Vec2, digit: u8, scale: i16, color: Color) -> Result<()> {
+    let glyph = DIGIT_FONT[digit as usize];
+    for (row, bits) in glyph.into_iter().enumerate() {
+        for col in 0..DIGIT_WIDTH {
+            let set = bits & (1 << (DIGIT_WIDTH - 1 - col)) != 0;
+            let pixel_color = if set { color } else { BLACK };
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    let pos = top_left + I16Vec2::new(col * scale + dx, row as i16 * scale + dy);
+                    client.write_pixel(pos.x as u16, pos.y as u16, pixel_color)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Draws `value` as a row of scoreboard digits starting at `top_left`.
+fn draw_number(client: &mut Client, top_left: I16Vec2, value: u32, scale: i16, color: Color) -> Result<()> {
+    let mut digits = vec![(value % 10) as u8];
+    let mut rest = value / 10;
+    while rest > 0 {
+        digits.push((rest % 10) as u8);
+        rest /= 10;
+    }
+    digits.reverse();
+
+    let digit_advance = (DIGIT_WIDTH + 1) * scale;
+    for (i, digit) in digits.into_iter().enumerate() {
+        let pos = top_left + I16Vec2::new(i as i16 * digit_advance, 0);
+        draw_digit(client, pos, digit, scale, color)?;
+    }
+    Ok(())
+}
+
+fn draw_score(game: SharedGameState, server: String, config: Arc<Config>) -> Result<()> {
+    let mut client = Client::new(TcpStream::connect(server)?, false, true);
+
+    let size = game.read().unwrap().size;
+    let player1_pos = I16Vec2::new(size.x / 4, size.y / 16);
+    let player2_pos = I16Vec2::new(size.x * 3 / 4, size.y / 16);
+
+    loop {
+        let game = game.read().unwrap();
+        let player1_score = game.player1_score;
+        let player2_score = game.player2_score;
+        drop(game);
+
+        draw_number(&mut client, player1_pos, player1_score, SCORE_SCALE, config.object_color)?;
+        draw_number(&mut client, player2_pos, player2_score, SCORE_SCALE, config.object_color)?;
+    }
+}
+
 fn handle_user_input(game: SharedGameState) -> Result<()> {
     let mut input = input::Interface::new();
     loop {
@@ -308,25 +638,81 @@ fn handle_user_input(game: SharedGameState) -> Result<()> {
     }
 }
 
+/// Parses `--ai1` / `--ai2`, optionally followed by `=<difficulty>` (a fraction in `(0.0, 1.0]`
+/// of the configured paddle speed), into the AI player it selects and the difficulty to run it at.
+fn parse_ai_flag(arg: &str) -> Result<Option<(AiControl, f32)>> {
+    let (flag, difficulty_str) = match arg.split_once('=') {
+        Some((flag, difficulty_str)) => (flag, Some(difficulty_str)),
+        None => (arg, None),
+    };
+    let control = match flag {
+        "--ai1" => AiControl::Player1,
+        "--ai2" => AiControl::Player2,
+        _ => return Ok(None),
+    };
+    let difficulty = match difficulty_str {
+        Some(difficulty_str) => difficulty_str
+            .parse()
+            .map_err(|_| anyhow!("invalid AI difficulty {difficulty_str:?}"))?,
+        None => DEFAULT_AI_DIFFICULTY,
+    };
+    if !(difficulty > 0.0) {
+        return Err(anyhow!(
+            "AI difficulty must be greater than 0.0, got {difficulty}"
+        ));
+    }
+    Ok(Some((control, difficulty.min(1.0))))
+}
+
 fn main() -> Result<()> {
-    let server = std::env::args()
-        .nth(1)
-        .ok_or(anyhow!("usage: pongxelflut [host:port]"))?;
+    let server = std::env::args().nth(1).ok_or(anyhow!(
+        "usage: pongxelflut [host:port] [--ai1|--ai2[=difficulty]] [config.json5]"
+    ))?;
+
+    // The AI flag and the config path can come in either order, so classify every extra
+    // argument instead of reading them off fixed positions.
+    let mut ai_selection = None;
+    let mut config_path = None;
+    for arg in std::env::args().skip(2) {
+        match parse_ai_flag(&arg)? {
+            Some(selection) => ai_selection = Some(selection),
+            None => config_path = Some(arg),
+        }
+    }
+    let (ai_control, ai_difficulty) =
+        ai_selection.unwrap_or((AiControl::None, DEFAULT_AI_DIFFICULTY));
+    let config = Arc::new(match config_path {
+        Some(path) => Config::load(&path)?,
+        None => Config::default(),
+    });
+
     let mut size_client = Client::new(TcpStream::connect(&server)?, false, true);
     let size = U16Vec2::from(size_client.read_screen_size()?);
     let size = I16Vec2::new(size.x as i16, size.y as i16);
 
-    let game = GameState::new(size);
+    let game = GameState::new(size, ai_control, ai_difficulty, config.clone());
     let game_for_ball = game.clone();
     let game_for_players = game.clone();
     let game_for_input = game.clone();
+    let game_for_score = game.clone();
     let server2 = server.clone();
     let server3 = server.clone();
+    let server4 = server.clone();
+    let config_for_ball = config.clone();
+    let config_for_players = config.clone();
+    let config_for_score = config.clone();
+    std::thread::spawn(move || loop {
+        let _ = draw_ball(game_for_ball.clone(), server2.clone(), config_for_ball.clone());
+    });
     std::thread::spawn(move || loop {
-        let _ = draw_ball(game_for_ball.clone(), server2.clone());
+        let _ = draw_players(
+            game_for_players.clone(),
+            server3.clone(),
+            config_for_players.clone(),
+        );
     });
     std::thread::spawn(move || loop {
-        let _ = draw_players(game_for_players.clone(), server3.clone());
+        let _ = draw_score(game_for_score.clone(), server4.clone(), config_for_score.clone());
     });
     std::thread::spawn(move || loop {
         let _ = handle_user_input(game_for_input.clone());
@@ -338,11 +724,11 @@ fn main() -> Result<()> {
         let now = Instant::now();
         delta += now - last_update;
 
-        if delta > FRAME_TIME {
+        if delta > config.frame_time {
             game.write().unwrap().update();
-            delta -= FRAME_TIME;
+            delta -= config.frame_time;
         } else {
-            sleep(FRAME_TIME / 2);
+            sleep(config.frame_time / 2);
         }
         last_update = now;
     }